@@ -115,11 +115,16 @@ impl Program {
   }
   #[inline]
   pub fn link(&self) -> Result<(), String> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("link", program = self.0).entered();
     unsafe { glLinkProgram(self.0) }
     if self.get_last_link_successful() {
       Ok(())
     } else {
-      Err(self.get_info_log())
+      let info_log = self.get_info_log();
+      #[cfg(feature = "tracing")]
+      tracing::error!(program = self.0, %info_log, "program link failed");
+      Err(info_log)
     }
   }
 
@@ -269,11 +274,16 @@ impl Program {
   }
   #[inline]
   pub fn validate(&self) -> Result<(), String> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("validate", program = self.0).entered();
     unsafe { glValidateProgram(self.0) }
     if self.get_validate_status() {
       Ok(())
     } else {
-      Err(self.get_info_log())
+      let info_log = self.get_info_log();
+      #[cfg(feature = "tracing")]
+      tracing::warn!(program = self.0, %info_log, "program validation failed");
+      Err(info_log)
     }
   }
   #[inline]
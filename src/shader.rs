@@ -65,11 +65,16 @@ impl Shader {
   }
   #[inline]
   pub fn compile(&self) -> Result<(), String> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("compile", shader = self.0).entered();
     unsafe { glCompileShader(self.0) };
     if self.get_last_compile_successful() {
       Ok(())
     } else {
-      Err(self.get_info_log())
+      let info_log = self.get_info_log();
+      #[cfg(feature = "tracing")]
+      tracing::error!(shader = self.0, %info_log, "shader compile failed");
+      Err(info_log)
     }
   }
   #[inline]
@@ -88,3 +93,8 @@ impl Shader {
     }
   }
 }
+
+#[inline]
+pub fn release_shader_compiler() {
+  unsafe { glReleaseShaderCompiler() }
+}
@@ -0,0 +1,85 @@
+use super::*;
+
+#[inline]
+pub fn set_viewport(win_width: u32, win_height: u32) {
+  unsafe { glViewport(0, 0, win_width, win_height) }
+}
+
+#[inline]
+pub fn set_clear_color(r: f32, g: f32, b: f32, a: f32) {
+  unsafe { glClearColor(r, g, b, a) }
+}
+
+#[inline]
+pub fn clear() {
+  const MASK: u32 =
+    GL_COLOR_BUFFER_BIT | GL_DEPTH_BUFFER_BIT | GL_STENCIL_BUFFER_BIT;
+  unsafe { glClear(MASK) }
+}
+
+#[inline]
+pub fn get_line_width_range() -> (f32, f32) {
+  let mut data: [f32; 2] = [0.0, 0.0];
+  unsafe { glGetFloatv(GL_ALIASED_LINE_WIDTH_RANGE, data.as_mut_ptr()) };
+  (data[0], data[1])
+}
+
+#[inline]
+pub fn get_point_width_range() -> (f32, f32) {
+  let mut data: [f32; 2] = [0.0, 0.0];
+  unsafe { glGetFloatv(GL_ALIASED_POINT_SIZE_RANGE, data.as_mut_ptr()) };
+  (data[0], data[1])
+}
+
+/// Sets if using the index type's max value as an index should trigger
+/// primitive restart.
+///
+/// Off by default.
+#[inline]
+pub fn set_primitive_restart_fixed_index_enabled(enabled: bool) {
+  unsafe {
+    if enabled {
+      glEnable(GL_PRIMITIVE_RESTART_FIXED_INDEX)
+    } else {
+      glDisable(GL_PRIMITIVE_RESTART_FIXED_INDEX)
+    }
+  }
+}
+
+#[inline]
+pub fn set_depth_range(near: f32, far: f32) {
+  unsafe { glDepthRangef(near, far) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u32)]
+pub enum FrontFace {
+  Clockwise = GL_CW,
+  CounterClockwise = GL_CCW,
+}
+#[inline]
+pub fn set_front_face(face: FrontFace) {
+  unsafe { glFrontFace(face as u32) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u32)]
+pub enum CullFace {
+  Front = GL_FRONT,
+  Back = GL_BACK,
+  FrontAndBack = GL_FRONT_AND_BACK,
+}
+#[inline]
+pub fn set_cull_face(face: CullFace) {
+  unsafe { glCullFace(face as u32) }
+}
+#[inline]
+pub fn set_cull_face_enabled(enabled: bool) {
+  unsafe {
+    if enabled {
+      glEnable(GL_CULL_FACE)
+    } else {
+      glDisable(GL_CULL_FACE)
+    }
+  }
+}